@@ -1,6 +1,24 @@
 use rand::Rng;
 
-use super::State;
+use std::collections::HashMap;
+use std::f64::consts::SQRT_2;
+use std::time::{Duration, Instant};
+
+use super::{Pos, State};
+
+/// Memoizes `search` results already computed for a given
+/// state and remaining depth, since identical positions are
+/// often reached by different move orders.
+///
+/// `PlacerPredictive::search_at_depth` keeps one of these *per root
+/// candidate* rather than a single table shared by the whole search,
+/// so that rayon can hand each candidate's table to its own worker
+/// without locking. That only captures move-order transpositions
+/// underneath a given root move; positions reached under two
+/// *different* root moves never share a cache entry even if they're
+/// identical. Revisit with a `Mutex`-guarded or sharded table if
+/// those cross-candidate hits turn out to matter in practice.
+type TranspositionTable = HashMap<(u128, u8), Outcome>;
 
 /// Represents a strategy that could be used by the dot.
 pub trait DotStrategy {
@@ -28,6 +46,7 @@ pub trait DotStrategy {
 /// A dumb strategy for the dot, causing it to move
 /// towards whichever state gives it the smallest
 /// distance to the edge.
+#[derive(Clone, Copy)]
 pub struct DumbPathfind;
 
 impl DotStrategy for DumbPathfind {
@@ -41,6 +60,7 @@ impl DotStrategy for DumbPathfind {
 /// A slightly less dumb strategy for the dot. It knows
 /// to take into account obstacles in its distance
 /// calculation.
+#[derive(Clone, Copy)]
 pub struct SmartPathfind;
 
 impl DotStrategy for SmartPathfind {
@@ -71,92 +91,963 @@ pub trait PlacerStrategy {
     }
 }
 
+/// The best outcome of a given branch in the game tree,
+/// from the placer's perspective. Shared by `PlacerPredictive`
+/// and `PlacerMinimax`, so that their results can be compared
+/// and combined on equal footing.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
+enum Outcome {
+    /// Losing in a given number of turns.
+    Lose(u8),
+    /// Playing, resulting in the dot being a given
+    /// distance away from the edge
+    Play(u8),
+    /// Winning in a given number of turns (this is
+    /// represented as negative so that winning in
+    /// less time is considered more of a success)
+    Win(i8),
+}
+
+impl Outcome {
+    /// Convert an outcome for this turn into
+    /// an outcome for the next turn.
+    fn inc(self) -> Self {
+        match self {
+            Self::Lose(n) => Self::Lose(n + 1),
+            Self::Play(n) => Self::Play(n),
+            Self::Win(n) => Self::Win(n - 1),
+        }
+    }
+}
+
+/// Drive an iterative-deepening search shared by `PlacerPredictive`
+/// and `PlacerMinimax`: call `search_depth` for depth 1, then depth
+/// 2, and so on, keeping the best move found at each depth that
+/// completes so a move is always available even if a deeper
+/// iteration never finishes. Stops early once a depth comes back
+/// with a forced win or loss, since no deeper search can improve on
+/// that. Returns the best choice found along with the deepest depth
+/// that completed.
+///
+/// Depth 1 is special-cased to run to completion regardless of
+/// `time_budget`, so a move is always available even if the budget
+/// is too tight to finish even the shallowest search.
+fn iterative_deepen(
+    time_budget: Duration,
+    mut search_depth: impl FnMut(u8, Instant) -> Option<(usize, Outcome)>,
+) -> (usize, u8) {
+    let deadline = Instant::now() + time_budget;
+
+    let mut depth = 1;
+    let (mut best, mut outcome) = search_depth(depth, Instant::now() + Duration::from_secs(60))
+        .expect("depth 1 search should always complete");
+
+    while !matches!(outcome, Outcome::Win(_) | Outcome::Lose(_)) {
+        let next_depth = depth + 1;
+        let Some((next_best, next_outcome)) = search_depth(next_depth, deadline) else {
+            // the deadline passed partway through this depth;
+            // keep the best move from the last completed one
+            break;
+        };
+        depth = next_depth;
+        best = next_best;
+        outcome = next_outcome;
+    }
+
+    (best, depth)
+}
+
 /// A strategy for the placer which is parameterized by
 /// an assumption about what strategy the dot will use
-/// next turn. It simply conducts a brute-force search
-/// to look for the best move down to a given depth in
-/// the game tree.
+/// next turn. It conducts a brute-force search of the game
+/// tree, deepening iteratively within a per-move time budget:
+/// depth 1 is searched first, then depth 2, and so on, keeping
+/// the best move found at each completed depth so a move is
+/// always available even if a deeper iteration never finishes.
 pub struct PlacerPredictive<R, S> {
     rng: R,
     dot_strategy: S,
+    time_budget: Duration,
+    /// The depth fully completed on the most recent move, for
+    /// display purposes.
+    last_depth: u8,
 }
 
 impl<R, S> PlacerPredictive<R, S> {
-    pub fn new(rng: R, dot_strategy: S) -> Self {
-        Self { rng, dot_strategy }
+    pub fn new(rng: R, dot_strategy: S, time_budget: Duration) -> Self {
+        Self { rng, dot_strategy, time_budget, last_depth: 0 }
     }
+
+    /// The search depth fully completed on the most recent move.
+    pub fn last_depth(&self) -> u8 {
+        self.last_depth
+    }
+}
+
+/// Determine the best outcome reachable within `n` turns.
+/// Assume it is the dot's turn and that it will move
+/// according to `dot_strategy`. Consults and populates `table` so that
+/// positions reached by different move orders are only
+/// evaluated once. Returns `None` if `deadline` passes before
+/// the search completes, so that a caller on a time budget can
+/// bail out mid-iteration instead of only between iterations.
+fn search<S: DotStrategy>(
+    state: State,
+    dot_strategy: &mut S,
+    n: u8,
+    table: &mut TranspositionTable,
+    deadline: Instant,
+) -> Option<Outcome> {
+    let key = (state.raw_bits(), n);
+    if let Some(&outcome) = table.get(&key) {
+        return Some(outcome);
+    }
+
+    if Instant::now() >= deadline {
+        return None;
+    }
+
+    let outcome = 'outcome: {
+        if n == 0 {
+            break 'outcome Outcome::Play(state.dot().dist_to_edge());
+        }
+
+        let dot_state = match dot_strategy.play(state) {
+            Some(s) => s,
+            None => break 'outcome Outcome::Lose(0),
+        };
+
+        // recursively determine: what the best way
+        // to respond to this?
+        let mut value = Outcome::Lose(0);
+        for ns in dot_state.branch_placer() {
+            let child = match ns {
+                None => Outcome::Win(0),
+                Some(new_state) => search(
+                    new_state,
+                    &mut *dot_strategy,
+                    n - 1,
+                    table,
+                    deadline,
+                )?.inc(),
+            };
+            if child > value {
+                value = child;
+            }
+        }
+        break 'outcome value;
+    };
+
+    table.insert(key, outcome);
+    Some(outcome)
 }
 
-const SEARCH_DEPTH: u8 = 4;
+impl<R: Rng, S: DotStrategy + Clone + Sync> PlacerPredictive<R, S> {
+    /// Evaluate every candidate at a single depth, in parallel,
+    /// and return the index of the best one along with its outcome.
+    /// `tables` holds one transposition table per candidate (see
+    /// `TranspositionTable`'s doc comment for why it's per-candidate
+    /// rather than shared), reused across the increasing depths of a
+    /// single `preferred_state` call (rather than recreated per
+    /// call) so that a state with the same remaining depth reached
+    /// at a shallower iteration is still a hit once deeper
+    /// iterations reach it too. Returns `None` if `deadline` passes
+    /// before every candidate finishes.
+    fn search_at_depth(
+        &mut self,
+        choices: &[State],
+        tables: &mut [TranspositionTable],
+        depth: u8,
+        deadline: Instant,
+    ) -> Option<(usize, Outcome)> {
+        use rayon::prelude::*;
+
+        // draw a single salt up front rather than threading a
+        // shared `Rng` through the parallel candidates below
+        let salt: u64 = self.rng.gen();
+        let dot_strategy = &self.dot_strategy;
+
+        let results: Option<Vec<(Outcome, u64)>> = choices.par_iter()
+            .zip(tables.par_iter_mut())
+            .enumerate()
+            .map(|(i, (&choice, table))| {
+                // each worker gets its own strategy so that
+                // `search` never has to share `&dot_strategy`
+                // across threads
+                let mut dot_strategy = dot_strategy.clone();
+                let outcome = search(choice, &mut dot_strategy, depth, table, deadline)?;
+
+                // fold the salt with the candidate's index into a
+                // deterministic tiebreak, so the result stays
+                // reproducible even though candidates are no longer
+                // scored (and thus tiebroken) in a fixed order
+                let tiebreak = salt ^ (i as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+
+                Some((outcome, tiebreak))
+            })
+            .collect();
+
+        let (idx, (outcome, _)) = results?.into_iter()
+            .enumerate()
+            .max_by_key(|&(_, key)| key)
+            .unwrap();
+
+        Some((idx, outcome))
+    }
+}
 
-impl<R: Rng, S: DotStrategy> PlacerStrategy for PlacerPredictive<R, S> {
+impl<R: Rng, S: DotStrategy + Clone + Sync> PlacerStrategy for PlacerPredictive<R, S> {
     fn preferred_state(&mut self, choices: &[State]) -> usize {
-        /// The best outcome of a given branch in the game tree.
-        #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug)]
-        enum Outcome {
-            /// Losing in a given number of turns.
-            Lose(u8),
-            /// Playing, resulting in the dot being a given
-            /// distance away from the edge
-            Play(u8),
-            /// Winning in a given number of turns (this is
-            /// represented as negative so that winning in
-            /// less time is considered more of a success)
-            Win(i8),
-        }
-
-        impl Outcome {
-            /// Convert an outcome for this turn into
-            /// an outcome for the next turn.
-            fn inc(self) -> Self {
-                match self {
-                    Self::Lose(n) => Self::Lose(n + 1),
-                    Self::Play(n) => Self::Play(n),
-                    Self::Win(n) => Self::Win(n - 1),
-                }
+        // one table per candidate, cleared at the start of every
+        // move to bound memory, but carried across this move's own
+        // depth iterations so earlier, shallower work is reused
+        let mut tables: Vec<TranspositionTable> =
+            choices.iter().map(|_| TranspositionTable::new()).collect();
+
+        let (best, depth) = iterative_deepen(self.time_budget, |depth, deadline| {
+            self.search_at_depth(choices, &mut tables, depth, deadline)
+        });
+        self.last_depth = depth;
+
+        best
+    }
+}
+
+/// Rough ordering key for the dot's candidate moves during
+/// `min_node`, used to try the move most dangerous to the placer
+/// first (escaping outright, or getting closer to the edge) so
+/// that alpha-beta pruning can discard the rest sooner.
+fn dot_move_order(ns: Option<Option<State>>) -> u8 {
+    match ns {
+        Some(None) => 0,
+        Some(Some(s)) => 1 + s.dot().dist_to_edge(),
+        None => u8::MAX,
+    }
+}
+
+/// Rough ordering key for the placer's candidate moves during
+/// `max_node`, for the same reason as `dot_move_order`: moves that
+/// look most promising for the placer (winning outright, or
+/// lengthening the dot's escape route the most) are tried first.
+fn placer_move_order(ns: Option<State>) -> u16 {
+    match ns {
+        None => 0,
+        // `max_node` wants its best moves explored first, so unlike
+        // `dot_move_order` this sorts descending by escape distance:
+        // subtracting from `u16::MAX` keeps ties with `None` (an
+        // outright win, which must stay first) impossible
+        Some(s) => u16::MAX - u16::from(s.dist_to_reach_edge().unwrap_or(SEALED_ESCAPE_DISTANCE)),
+    }
+}
+
+/// Assume it is the dot's turn, and that it plays
+/// adversarially (i.e. to minimize the placer's outcome).
+/// Prune branches using the `(alpha, beta)` bounds, which
+/// bracket the range of outcomes still worth considering.
+/// Returns `None` if `deadline` passes before the search
+/// completes.
+fn min_node(state: State, n: u8, alpha: Outcome, mut beta: Outcome, deadline: Instant) -> Option<Outcome> {
+    if n == 0 {
+        return Some(Outcome::Play(state.dot().dist_to_edge()));
+    }
+
+    if Instant::now() >= deadline {
+        return None;
+    }
+
+    let mut branches = state.branch_dot();
+    branches.sort_by_key(|&ns| dot_move_order(ns));
+
+    let mut value = Outcome::Win(0);
+    for ns in branches {
+        let child = match ns {
+            None => continue,
+            Some(None) => Outcome::Lose(0),
+            Some(Some(dot_state)) => max_node(dot_state, n, alpha, beta, deadline)?,
+        };
+        if child < value {
+            value = child;
+        }
+        if value < beta {
+            beta = value;
+        }
+        if beta <= alpha {
+            break;
+        }
+    }
+    Some(value)
+}
+
+/// Assume it is the placer's turn, and that it plays
+/// adversarially (i.e. to maximize its own outcome).
+/// Prune branches using the `(alpha, beta)` bounds, which
+/// bracket the range of outcomes still worth considering.
+/// Returns `None` if `deadline` passes before the search
+/// completes.
+fn max_node(state: State, n: u8, mut alpha: Outcome, beta: Outcome, deadline: Instant) -> Option<Outcome> {
+    if Instant::now() >= deadline {
+        return None;
+    }
+
+    let mut branches: Vec<Option<State>> = state.branch_placer().collect();
+    branches.sort_by_key(|&ns| placer_move_order(ns));
+
+    // `min_node`'s result goes through `inc` below, so the bounds
+    // handed to it are mapped back through the inverse of `inc`
+    // for its cutoffs to agree with ours
+    let undo_inc = |o: Outcome| match o {
+        Outcome::Lose(n) => Outcome::Lose(n.saturating_sub(1)),
+        Outcome::Play(n) => Outcome::Play(n),
+        Outcome::Win(n) => Outcome::Win(n.saturating_add(1)),
+    };
+
+    let mut value = Outcome::Lose(0);
+    for ns in branches {
+        let child = match ns {
+            None => Outcome::Win(0),
+            Some(new_state) => min_node(new_state, n - 1, undo_inc(alpha), undo_inc(beta), deadline)?.inc(),
+        };
+        if child > value {
+            value = child;
+        }
+        if value > alpha {
+            alpha = value;
+        }
+        if alpha >= beta {
+            break;
+        }
+    }
+    Some(value)
+}
+
+/// A strategy for the placer which runs a true adversarial
+/// minimax search over the game tree, rather than assuming
+/// the dot follows a fixed strategy. The dot is assumed to
+/// always play the move that is worst for the placer, which
+/// makes this strategy robust against any opponent at the
+/// cost of being unable to exploit a weaker one. Alpha-beta
+/// pruning (helped along by `dot_move_order`/`placer_move_order`)
+/// keeps the search tractable, and like `PlacerPredictive` it
+/// deepens iteratively within a per-move time budget rather than
+/// committing to a fixed depth up front.
+pub struct PlacerMinimax<R> {
+    rng: R,
+    time_budget: Duration,
+}
+
+impl<R> PlacerMinimax<R> {
+    pub fn new(rng: R, time_budget: Duration) -> Self {
+        Self { rng, time_budget }
+    }
+}
+
+impl<R: Rng> PlacerMinimax<R> {
+    /// Evaluate every candidate at a single depth, returning the
+    /// index of the best one along with its outcome, or `None` if
+    /// `deadline` passes before every candidate finishes.
+    fn search_at_depth(&mut self, choices: &[State], depth: u8, deadline: Instant) -> Option<(usize, Outcome)> {
+        (0..choices.len())
+            .map(|i| {
+                let outcome = min_node(choices[i], depth, Outcome::Lose(0), Outcome::Win(0), deadline)?;
+                // tiebreak using a random value to avoid always
+                // choosing the first option
+                Some((i, (outcome, self.rng.gen::<u8>())))
+            })
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .max_by_key(|&(_, key)| key)
+            .map(|(i, (outcome, _))| (i, outcome))
+    }
+}
+
+impl<R: Rng> PlacerStrategy for PlacerMinimax<R> {
+    fn preferred_state(&mut self, choices: &[State]) -> usize {
+        let (best, _depth) = iterative_deepen(self.time_budget, |depth, deadline| {
+            self.search_at_depth(choices, depth, deadline)
+        });
+
+        best
+    }
+}
+
+/// Identifies one of the two players, either as whoever is
+/// about to move or as whoever has won.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum Side {
+    Dot,
+    Placer,
+}
+
+impl Side {
+    fn other(self) -> Self {
+        match self {
+            Self::Dot => Self::Placer,
+            Self::Placer => Self::Dot,
+        }
+    }
+}
+
+/// One way a side to move could branch from a state.
+#[derive(Clone, Copy)]
+enum Branch {
+    /// The game continues in this new state.
+    Ongoing(State),
+    /// This move immediately wins the game for the given side.
+    Win(Side),
+}
+
+/// List every way `side` could move from `state`.
+fn branches(state: State, side: Side) -> Vec<Branch> {
+    match side {
+        Side::Dot => state.branch_dot().iter()
+            .filter_map(|&ns| match ns {
+                None => None,
+                Some(None) => Some(Branch::Win(Side::Dot)),
+                Some(Some(s)) => Some(Branch::Ongoing(s)),
+            })
+            .collect(),
+        Side::Placer => state.branch_placer()
+            .map(|ns| match ns {
+                None => Branch::Win(Side::Placer),
+                Some(s) => Branch::Ongoing(s),
+            })
+            .collect(),
+    }
+}
+
+/// A node in the Monte Carlo search tree.
+struct McNode {
+    state: State,
+    /// The side whose move produced this node (for the
+    /// root-level choices, this is whichever side just
+    /// branched to produce them). Moves alternate sides,
+    /// so this also identifies whose perspective `w` is
+    /// accumulated from.
+    mover_in: Side,
+    /// Set once the game has ended upon reaching this node,
+    /// in which case `mover_in` is the side that won.
+    winner: Option<Side>,
+    /// Moves from this state that have not yet been added
+    /// to the tree.
+    untried: Vec<Branch>,
+    /// Indices into the arena of children already visited.
+    children: Vec<usize>,
+    /// Number of playouts that have passed through this node.
+    n: u32,
+    /// Total reward, from `mover_in`'s perspective, accumulated
+    /// over all those playouts.
+    w: f64,
+}
+
+impl McNode {
+    fn ongoing(state: State, mover_in: Side) -> Self {
+        Self {
+            state,
+            mover_in,
+            winner: None,
+            untried: branches(state, mover_in.other()),
+            children: Vec::new(),
+            n: 0,
+            w: 0.0,
+        }
+    }
+
+    fn terminal(winner: Side) -> Self {
+        Self {
+            state: State::default(),
+            mover_in: winner,
+            winner: Some(winner),
+            untried: Vec::new(),
+            children: Vec::new(),
+            n: 0,
+            w: 0.0,
+        }
+    }
+
+    /// The side to move from this node, or `None` if the
+    /// game already ended upon reaching it.
+    fn to_move(&self) -> Option<Side> {
+        if self.winner.is_some() { None } else { Some(self.mover_in.other()) }
+    }
+}
+
+/// Score a child for selection, treating an unvisited child
+/// as having infinite priority.
+fn ucb1(parent_n: u32, child: &McNode) -> f64 {
+    if child.n == 0 {
+        return f64::INFINITY;
+    }
+    let exploit = child.w / f64::from(child.n);
+    let explore = SQRT_2 * (f64::from(parent_n).ln() / f64::from(child.n)).sqrt();
+    exploit + explore
+}
+
+/// Play uniformly random moves from `state` (with `side` to
+/// move first) until the game ends, and return the winner.
+fn rollout<R: Rng>(mut state: State, mut side: Side, rng: &mut R) -> Side {
+    loop {
+        let options = branches(state, side);
+        if options.is_empty() {
+            // `side` has no legal moves at all, e.g. the board
+            // filled up before the placer managed to trap the dot;
+            // treat it as a loss for `side` rather than index into
+            // an empty slice below
+            return side.other();
+        }
+        match options[rng.gen_range(0, options.len())] {
+            Branch::Win(winner) => return winner,
+            Branch::Ongoing(s) => {
+                state = s;
+                side = side.other();
             }
         }
+    }
+}
 
-        /// Determine the best outcome reachable within `n` turns.
-        /// Assume it is the dot's turn and that it will move
-        /// according to `s`.
-        fn search<S: DotStrategy>(
-            state: State,
-            dot_strategy: &mut S,
-            n: u8
-        ) -> Outcome {
-            if n == 0 {
-                Outcome::Play(state.dot().dist_to_edge())
-            } else {
-                let dot_state = match dot_strategy.play(state) {
-                    Some(s) => s,
-                    None => return Outcome::Lose(0),
-                };
-
-                // recursively determine: what the best way
-                // to respond to this?
-                dot_state.branch_placer()
-                    .map(|ns| match ns {
-                        None => Outcome::Win(0),
-                        Some(new_state) => search(
-                            new_state,
-                            &mut *dot_strategy,
-                            n - 1
-                        ).inc()
-                    })
-                    .max()
-                    .unwrap()
+/// Run one selection/expansion/simulation/backpropagation
+/// cycle starting from the existing node `root`.
+fn playout<R: Rng>(arena: &mut Vec<McNode>, root: usize, rng: &mut R) {
+    // SELECTION: descend by UCB1 while every node along the
+    // way is already fully expanded, recording the path taken
+    // so the result can be backpropagated afterwards.
+    let mut path = vec![root];
+    let mut idx = root;
+    while arena[idx].winner.is_none()
+    && arena[idx].untried.is_empty()
+    && !arena[idx].children.is_empty() {
+        let parent_n = arena[idx].n;
+        idx = arena[idx].children.iter().copied()
+            .max_by(|&a, &b| ucb1(parent_n, &arena[a])
+                .partial_cmp(&ucb1(parent_n, &arena[b]))
+                .unwrap())
+            .unwrap();
+        path.push(idx);
+    }
+
+    // EXPANSION: add one unvisited child, if this node isn't
+    // a terminal state already.
+    if arena[idx].winner.is_none() && !arena[idx].untried.is_empty() {
+        let branch = arena[idx].untried.pop().unwrap();
+        let mover = arena[idx].to_move().unwrap();
+        let child = match branch {
+            Branch::Win(side) => McNode::terminal(side),
+            Branch::Ongoing(s) => McNode::ongoing(s, mover),
+        };
+        arena.push(child);
+        idx = arena.len() - 1;
+        arena[path[path.len() - 1]].children.push(idx);
+        path.push(idx);
+    }
+
+    // SIMULATION
+    let winner = match arena[idx].winner {
+        Some(side) => side,
+        None => rollout(arena[idx].state, arena[idx].to_move().unwrap(), rng),
+    };
+
+    // BACKPROPAGATION: perspective flips automatically since
+    // `mover_in` alternates with every ply along the path.
+    for &i in &path {
+        arena[i].n += 1;
+        if arena[i].mover_in == winner {
+            arena[i].w += 1.0;
+        }
+    }
+}
+
+/// A Monte Carlo Tree Search strategy, usable by either the
+/// dot or the placer. Rather than searching to a fixed depth,
+/// it spends a fixed budget of playouts exploring the most
+/// promising lines first, which lets it find a good move even
+/// where a depth-limited search like `PlacerPredictive` would
+/// be too shallow to see a win.
+pub struct Mcts<R> {
+    rng: R,
+    iterations: u32,
+}
+
+impl<R> Mcts<R> {
+    pub fn new(rng: R, iterations: u32) -> Self {
+        Self { rng, iterations }
+    }
+}
+
+impl<R: Rng> Mcts<R> {
+    /// Search starting from `choices`, each of which is a
+    /// candidate state reached just after `mover` moved.
+    /// Return the index of whichever choice ends up visited
+    /// the most.
+    fn search(&mut self, choices: &[State], mover: Side) -> usize {
+        let mut arena: Vec<McNode> = choices.iter()
+            .map(|&s| McNode::ongoing(s, mover))
+            .collect();
+        let n_choices = arena.len();
+
+        for played in 0..self.iterations {
+            // `played` is exactly the total number of playouts
+            // run through the choices so far, which is what
+            // UCB1 wants as the parent visit count at this level
+            let root = (0..n_choices)
+                .max_by(|&a, &b| ucb1(played, &arena[a])
+                    .partial_cmp(&ucb1(played, &arena[b]))
+                    .unwrap())
+                .unwrap();
+            playout(&mut arena, root, &mut self.rng);
+        }
+
+        (0..n_choices).max_by_key(|&i| arena[i].n).unwrap()
+    }
+}
+
+impl<R: Rng> DotStrategy for Mcts<R> {
+    fn preferred_state(&mut self, choices: &[State]) -> usize {
+        self.search(choices, Side::Dot)
+    }
+}
+
+impl<R: Rng> PlacerStrategy for Mcts<R> {
+    fn preferred_state(&mut self, choices: &[State]) -> usize {
+        self.search(choices, Side::Placer)
+    }
+}
+
+/// Used in place of an actual distance when `dist_to_reach_edge`
+/// returns `None`, i.e. when the placer has already sealed the
+/// dot in completely.
+const SEALED_ESCAPE_DISTANCE: u8 = u8::MAX;
+
+/// A lightweight placer strategy that scores each candidate by
+/// its effect on the dot's freedom, rather than searching the
+/// game tree. This keeps it fast even at a large effective
+/// depth, complementing the exhaustive searchers above.
+pub struct PlacerInfluence {
+    /// Weight given to lengthening the dot's shortest escape route.
+    escape_weight: f64,
+    /// Weight given to directly blocking the dot's immediate neighbors.
+    surround_weight: f64,
+}
+
+impl PlacerInfluence {
+    pub fn new(escape_weight: f64, surround_weight: f64) -> Self {
+        Self { escape_weight, surround_weight }
+    }
+}
+
+impl Default for PlacerInfluence {
+    /// Weighs lengthening the escape route and directly
+    /// surrounding the dot equally.
+    fn default() -> Self {
+        Self::new(1.0, 1.0)
+    }
+}
+
+/// The number of the dot's immediate neighbors blocked in `state`.
+fn blocked_neighbors(state: State) -> u8 {
+    let mut count = 0;
+    for &n in &state.dot().neighbors() {
+        if let Some(pos) = n {
+            if state.has_filled(pos) {
+                count += 1;
             }
         }
+    }
+    count
+}
+
+/// BFS outward from `from` to find the distance to `to`, treating
+/// every position as traversable regardless of whether it's
+/// filled. Used only to break ties toward placements that land
+/// close to the dot. Mirrors the `u128` bitmask idiom
+/// `State::dist_to_reach_edge` uses to track visited cells.
+fn dist_between(from: Pos, to: Pos) -> u8 {
+    if from == to {
+        return 0;
+    }
+
+    let mut visited = 1u128 << from.0;
+    let mut frontier = vec![from];
+    let mut steps = 0u8;
+    while !frontier.is_empty() {
+        steps += 1;
+        let mut next_frontier = Vec::new();
+        for pos in frontier {
+            for &n in &pos.neighbors() {
+                if let Some(n) = n {
+                    if visited & (1u128 << n.0) != 0 {
+                        continue;
+                    }
+                    visited |= 1u128 << n.0;
+                    if n == to {
+                        return steps;
+                    }
+                    next_frontier.push(n);
+                }
+            }
+        }
+        frontier = next_frontier;
+    }
+    // unreachable in practice: `to` is always a real board position
+    u8::MAX
+}
+
+impl PlacerStrategy for PlacerInfluence {
+    fn preferred_state(&mut self, choices: &[State]) -> usize {
+        let influence = |state: State| {
+            let escape = f64::from(state.dist_to_reach_edge()
+                .unwrap_or(SEALED_ESCAPE_DISTANCE));
+            let surround = f64::from(blocked_neighbors(state));
+            self.escape_weight * escape + self.surround_weight * surround
+        };
+
+        // every candidate shares the same board except for the one
+        // cell it just placed, so intersecting their bits recovers
+        // that shared board, letting us isolate each candidate's
+        // own new cell below rather than whatever was filled by
+        // earlier turns
+        let baseline_bits = choices.iter()
+            .map(|s| s.raw_bits())
+            .fold(!0u128, |acc, bits| acc & bits);
+        let new_fill = |state: State| Pos((state.raw_bits() ^ baseline_bits).trailing_zeros() as u8);
+        let dot = choices[0].dot();
 
         (0..choices.len())
-            // tiebreak using a random value to avoid always
-            // choosing the last option
-            .max_by_key(|&i| (search(
-                choices[i],
-                &mut self.dot_strategy,
-                SEARCH_DEPTH
-            ), self.rng.gen::<u8>()))
+            // tie-break toward the candidate whose new placement
+            // lands closest to the dot
+            .max_by(|&a, &b| {
+                influence(choices[a]).partial_cmp(&influence(choices[b]))
+                    .unwrap()
+                    .then_with(|| dist_between(dot, new_fill(choices[b]))
+                        .cmp(&dist_between(dot, new_fill(choices[a]))))
+            })
             .unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    fn seeded_state(seed: u64) -> State {
+        let mut rng = StdRng::seed_from_u64(seed);
+        State::new(&mut rng)
+    }
+
+    // regression test for the chunk0-1 fix: `placer_move_order`
+    // previously sorted ascending by escape distance, trying the
+    // placer's *worst* moves first and defeating alpha-beta pruning
+    #[test]
+    fn placer_move_order_prefers_longer_escape_routes_first() {
+        let state = seeded_state(1);
+        let mut branches: Vec<Option<State>> = state.branch_placer().collect();
+        branches.sort_by_key(|&ns| placer_move_order(ns));
+
+        let escapes: Vec<u8> = branches.iter()
+            .filter_map(|&ns| ns.map(|s| s.dist_to_reach_edge().unwrap_or(SEALED_ESCAPE_DISTANCE)))
+            .collect();
+        for pair in escapes.windows(2) {
+            assert!(
+                pair[0] >= pair[1],
+                "placer moves were not sorted best-first: {:?}", escapes,
+            );
+        }
+    }
+
+    #[test]
+    fn dot_move_order_prefers_moves_closest_to_the_edge_first() {
+        let state = seeded_state(2);
+        let mut branches = state.branch_dot();
+        branches.sort_by_key(|&ns| dot_move_order(ns));
+
+        let dists: Vec<u8> = branches.iter()
+            .filter_map(|&ns| match ns {
+                Some(Some(s)) => Some(s.dot().dist_to_edge()),
+                _ => None,
+            })
+            .collect();
+        for pair in dists.windows(2) {
+            assert!(
+                pair[0] <= pair[1],
+                "dot moves were not sorted best-first: {:?}", dists,
+            );
+        }
+    }
+
+    // regression test for the chunk0-2 fix: `rollout` used to index
+    // into `branches(state, side)` unconditionally, panicking if a
+    // side ever had no legal moves
+    #[test]
+    fn rollout_terminates_without_panicking() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let state = seeded_state(3);
+        let _winner = rollout(state, Side::Placer, &mut rng);
+    }
+
+    #[test]
+    fn playout_runs_without_panicking() {
+        let mut rng = StdRng::seed_from_u64(4);
+        let state = seeded_state(4);
+        let mut arena = vec![McNode::ongoing(state, Side::Placer)];
+        for _ in 0..50 {
+            playout(&mut arena, 0, &mut rng);
+        }
+        assert_eq!(arena[0].n, 50);
+    }
+
+    // regression test for the chunk0-5 fix: `dist_between` (used to
+    // tie-break `PlacerInfluence` toward the candidate's own newly
+    // placed cell) should measure plain hex-grid distance between
+    // two positions, not distance to the nearest filled cell overall
+    #[test]
+    fn dist_between_same_position_is_zero() {
+        let state = seeded_state(5);
+        let dot = state.dot();
+        assert_eq!(dist_between(dot, dot), 0);
+    }
+
+    #[test]
+    fn dist_between_neighbor_is_one() {
+        let state = seeded_state(6);
+        let dot = state.dot();
+        let neighbor = dot.neighbors().iter()
+            .flatten()
+            .next()
+            .copied()
+            .expect("a board position always has at least one neighbor");
+        assert_eq!(dist_between(dot, neighbor), 1);
+    }
+
+    /// Brute-force reimplementation of `min_node`, without any
+    /// alpha-beta pruning, used only as ground truth to check the
+    /// pruned search below.
+    fn brute_min_node(state: State, n: u8) -> Outcome {
+        if n == 0 {
+            return Outcome::Play(state.dot().dist_to_edge());
+        }
+        state.branch_dot().iter()
+            .filter_map(|&ns| match ns {
+                None => None,
+                Some(None) => Some(Outcome::Lose(0)),
+                Some(Some(dot_state)) => Some(brute_max_node(dot_state, n)),
+            })
+            .min()
+            .unwrap()
+    }
+
+    /// Brute-force reimplementation of `max_node`, without any
+    /// alpha-beta pruning, used only as ground truth to check the
+    /// pruned search below.
+    fn brute_max_node(state: State, n: u8) -> Outcome {
+        state.branch_placer()
+            .map(|ns| match ns {
+                None => Outcome::Win(0),
+                Some(new_state) => brute_min_node(new_state, n - 1).inc(),
+            })
+            .max()
+            .unwrap()
+    }
+
+    // regression test for the chunk0-1 fix: `min_node`/`max_node`
+    // thread hand-rolled alpha-beta bounds through mutual recursion,
+    // which is easy to get subtly wrong (e.g. an off-by-one in the
+    // `<=`/`>=` cutoffs) in a way that silently changes which move
+    // is chosen rather than panicking; check the pruned result
+    // against an unpruned brute-force search of the same tree
+    #[test]
+    fn min_node_matches_brute_force_minimax() {
+        let depth = 2;
+        let no_deadline = Instant::now() + Duration::from_secs(30);
+        for seed in 0..8 {
+            let state = seeded_state(seed);
+            let pruned = min_node(state, depth, Outcome::Lose(0), Outcome::Win(0), no_deadline)
+                .expect("search should complete well within the deadline");
+            let brute = brute_min_node(state, depth);
+            assert_eq!(pruned, brute, "seed {seed}");
+        }
+    }
+
+    // regression test for the chunk0-3 fix: `search_at_depth` runs
+    // every candidate in parallel over rayon and breaks outcome ties
+    // with a salt drawn from `self.rng`, specifically so the winner
+    // stays reproducible for a fixed seed even though rayon may
+    // visit candidates in a different order from run to run
+    #[test]
+    fn search_at_depth_is_deterministic_for_a_fixed_seed() {
+        let state = seeded_state(9);
+        let choices: Vec<State> = state.branch_placer().flatten().collect();
+        let deadline = Instant::now() + Duration::from_secs(10);
+
+        let run = || {
+            let mut placer =
+                PlacerPredictive::new(StdRng::seed_from_u64(42), SmartPathfind, Duration::from_secs(10));
+            let mut tables: Vec<TranspositionTable> =
+                choices.iter().map(|_| TranspositionTable::new()).collect();
+            placer.search_at_depth(&choices, &mut tables, 2, deadline)
+                .expect("search should complete well within the deadline")
+        };
+
+        let first = run();
+        for _ in 0..4 {
+            assert_eq!(run(), first);
+        }
+    }
+
+    // regression test for the chunk0-4 fix: `search`'s transposition
+    // table should return the exact outcome it cached for a given
+    // (state, depth) key on a later hit, not merely *some* plausible
+    // outcome
+    #[test]
+    fn search_transposition_table_hit_matches_original_computation() {
+        let state = seeded_state(10);
+        let mut dot_strategy = SmartPathfind;
+        let depth = 2;
+        let deadline = Instant::now() + Duration::from_secs(10);
+
+        let mut table = TranspositionTable::new();
+        let first = search(state, &mut dot_strategy, depth, &mut table, deadline)
+            .expect("search should complete well within the deadline");
+        assert_eq!(table.get(&(state.raw_bits(), depth)), Some(&first));
+
+        let second = search(state, &mut dot_strategy, depth, &mut table, deadline)
+            .expect("search should complete well within the deadline");
+        assert_eq!(second, first);
+    }
+
+    // regression test for the chunk0-6 fix: `iterative_deepen` should
+    // stop requesting deeper searches as soon as one comes back with
+    // a forced win or loss, since no deeper search can improve on it
+    #[test]
+    fn iterative_deepen_stops_once_a_depth_finds_a_forced_outcome() {
+        let mut calls = 0;
+        let (best, depth) = iterative_deepen(Duration::from_secs(10), |depth, _deadline| {
+            calls += 1;
+            match depth {
+                1 => Some((0, Outcome::Play(5))),
+                2 => Some((1, Outcome::Win(2))),
+                _ => panic!("searched depth {depth} after a forced outcome was already found"),
+            }
+        });
+
+        assert_eq!((best, depth), (1, 2));
+        assert_eq!(calls, 2);
+    }
+
+    // regression test for the chunk0-6 fix: if a deeper iteration's
+    // search never completes before the deadline, `iterative_deepen`
+    // should fall back to the best move found at the last depth that
+    // did complete, rather than losing that result
+    #[test]
+    fn iterative_deepen_falls_back_to_last_completed_depth() {
+        let (best, depth) = iterative_deepen(Duration::from_secs(10), |depth, _deadline| {
+            match depth {
+                1 => Some((0, Outcome::Play(5))),
+                2 => Some((1, Outcome::Play(3))),
+                // the deadline passed partway through this depth
+                _ => None,
+            }
+        });
+
+        assert_eq!((best, depth), (1, 2));
+    }
+}
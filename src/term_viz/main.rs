@@ -3,6 +3,7 @@ use strategy::DotStrategy as _;
 use strategy::PlacerStrategy as _;
 
 use std::io::{self, Write};
+use std::time::Duration;
 
 fn clear_screen() {
     print!("\x1b[H\x1b[2J");
@@ -16,8 +17,9 @@ pub fn main() -> io::Result<()> {
     let mut rng = rand::thread_rng();
     let mut state = State::new(&mut rng);
 
-    let mut placer_strategy = s::PlacerPredictive::new(rng, s::SmartPathfind);
-    let mut dot_strategy = s::SmartPathfind;
+    let mut placer_strategy = s::PlacerPredictive::new(
+        rng, s::SmartPathfind, Duration::from_millis(500));
+    let mut dot_strategy = s::Mcts::new(rand::thread_rng(), 200);
 
     loop {
         // perform placer actions
@@ -28,6 +30,8 @@ pub fn main() -> io::Result<()> {
 
         if let Some(s) = placer_strategy.play(state) {
             state = s;
+            writeln!(stdout, "reached depth {}", placer_strategy.last_depth())?;
+            stdout.flush()?;
         } else {
             stdout.write_all(b"placer\n")?;
             stdout.flush()?;
@@ -40,7 +44,9 @@ pub fn main() -> io::Result<()> {
         clear_screen();
         state.display(&mut stdout)?;
 
-        if let Some(s) = dot_strategy.play(state) {
+        // `Mcts` implements both `DotStrategy` and `PlacerStrategy`,
+        // each with its own `play`, so the call must be disambiguated
+        if let Some(s) = s::DotStrategy::play(&mut dot_strategy, state) {
             state = s;
         } else {
             stdout.write_all(b"dot\n")?;